@@ -16,8 +16,8 @@ function calculateOperationFees(operations) {
   };
 
   operations.forEach((operation) => {
-    fees.storageFee += operation.getProcessingCost();
-    fees.processingFee += operation.getStorageCost();
+    fees.storageFee += operation.getStorageCost();
+    fees.processingFee += operation.getProcessingCost();
   });
 
   fees.storageFee *= FEE_MULTIPLIER;
@@ -0,0 +1,17 @@
+const calculateOperationFees = require('../../../lib/stateTransition/fee/calculateOperationFees');
+const PreCalculatedOperation = require('../../../lib/stateTransition/fee/operations/PreCalculatedOperation');
+const { FEE_MULTIPLIER } = require('../../../lib/stateTransition/fee/constants');
+
+describe('calculateOperationFees', () => {
+  it('should sum storage and processing costs into the matching fees', () => {
+    const result = calculateOperationFees([
+      new PreCalculatedOperation(100, 1),
+      new PreCalculatedOperation(20, 3),
+    ]);
+
+    expect(result).to.deep.equal({
+      storageFee: 120 * FEE_MULTIPLIER,
+      processingFee: 4 * FEE_MULTIPLIER,
+    });
+  });
+});
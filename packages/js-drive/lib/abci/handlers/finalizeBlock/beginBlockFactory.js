@@ -2,6 +2,7 @@ const NotSupportedNetworkProtocolVersionError = require('../errors/NotSupportedN
 const NetworkProtocolVersionIsNotSetError = require('../errors/NetworkProtocolVersionIsNotSetError');
 
 const timeToMillis = require('../../../util/timeToMillis');
+const BlockInfo = require('../../../blockExecution/BlockInfo');
 
 /**
  * Begin Block
@@ -147,6 +148,7 @@ function beginBlockFactory(
     if (isSimplifiedMasternodeListUpdated) {
       const synchronizeMasternodeIdentitiesResult = await synchronizeMasternodeIdentities(
         coreChainLockedHeight,
+        new BlockInfo(rsRequest.blockHeight, rsRequest.blockTimeMs),
       );
 
       const {
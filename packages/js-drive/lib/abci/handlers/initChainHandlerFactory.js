@@ -6,6 +6,9 @@ const {
   },
 } = require('@dashevo/abci/types');
 
+const BlockInfo = require('../../blockExecution/BlockInfo');
+const timeToMillis = require('../../util/timeToMillis');
+
 /**
  * Init Chain ABCI handler
  *
@@ -71,10 +74,16 @@ function initChainHandlerFactory(
       { useTransaction: true },
     );
 
-    await registerSystemDataContracts(consensusLogger, time);
+    const genesisBlockInfo = new BlockInfo(
+      request.initialHeight.toNumber(),
+      timeToMillis(time.seconds.toNumber(), time.nanos || 0),
+    );
+
+    await registerSystemDataContracts(consensusLogger, genesisBlockInfo);
 
     const synchronizeMasternodeIdentitiesResult = await synchronizeMasternodeIdentities(
       initialCoreChainLockedHeight,
+      genesisBlockInfo,
     );
 
     const {
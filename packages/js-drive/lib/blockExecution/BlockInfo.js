@@ -0,0 +1,71 @@
+const Long = require('long');
+
+const timeToMillis = require('../util/timeToMillis');
+const BlockExecutionContextIsNotSetError = require('./errors/BlockExecutionContextIsNotSetError');
+
+class BlockInfo {
+  /**
+   * @type {number}
+   */
+  #height;
+
+  /**
+   * @type {number}
+   */
+  #timeMs;
+
+  /**
+   * @param {number} height
+   * @param {number} timeMs
+   */
+  constructor(height, timeMs) {
+    this.#height = height;
+    this.#timeMs = timeMs;
+  }
+
+  /**
+   * @return {number}
+   */
+  getHeight() {
+    return this.#height;
+  }
+
+  /**
+   * @return {number}
+   */
+  getTimeMs() {
+    return this.#timeMs;
+  }
+
+  /**
+   * Get block time as a Date which is accepted by RS Drive
+   *
+   * @return {Date}
+   */
+  getTime() {
+    return new Date(this.#timeMs);
+  }
+
+  /**
+   * Create block info from current block execution context
+   *
+   * @param {BlockExecutionContext} blockExecutionContext
+   * @throws {BlockExecutionContextIsNotSetError}
+   * @return {BlockInfo}
+   */
+  static createFromBlockExecutionContext(blockExecutionContext) {
+    const height = blockExecutionContext.getHeight();
+    const time = blockExecutionContext.getTime();
+
+    if (height === null || height === undefined || !time) {
+      throw new BlockExecutionContextIsNotSetError();
+    }
+
+    return new BlockInfo(
+      Long.fromValue(height).toNumber(),
+      timeToMillis(Long.fromValue(time.seconds).toNumber(), time.nanos || 0),
+    );
+  }
+}
+
+module.exports = BlockInfo;
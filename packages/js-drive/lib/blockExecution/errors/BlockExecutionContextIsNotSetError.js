@@ -0,0 +1,9 @@
+const DriveError = require('../../errors/DriveError');
+
+class BlockExecutionContextIsNotSetError extends DriveError {
+  constructor() {
+    super('Block execution context height and time are not set');
+  }
+}
+
+module.exports = BlockExecutionContextIsNotSetError;
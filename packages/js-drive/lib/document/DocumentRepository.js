@@ -25,13 +25,14 @@ class DocumentRepository {
    * Create document
    *
    * @param {Document} document
+   * @param {BlockInfo} blockInfo
    * @param {Object} [options]
    * @param {boolean} [options.useTransaction=false]
    * @param {boolean} [options.dryRun=false]
    *
    * @return {Promise<StorageResult<void>>}
    */
-  async create(document, options = {}) {
+  async create(document, blockInfo, options = {}) {
     let processingCost;
     let storageCost;
 
@@ -39,7 +40,7 @@ class DocumentRepository {
       ([storageCost, processingCost] = await this.storage.getDrive()
         .createDocument(
           document,
-          new Date('2022-03-17T15:08:26.132Z'),
+          blockInfo.getTime(),
           Boolean(options.useTransaction),
           Boolean(options.dryRun),
         ));
@@ -70,13 +71,14 @@ class DocumentRepository {
    * Update document
    *
    * @param {Document} document
+   * @param {BlockInfo} blockInfo
    * @param {Object} [options]
    * @param {boolean} [options.useTransaction=false]
    * @param {boolean} [options.dryRun=false]
    *
    * @return {Promise<StorageResult<void>>}
    */
-  async update(document, options = {}) {
+  async update(document, blockInfo, options = {}) {
     let processingCost;
     let storageCost;
 
@@ -84,7 +86,7 @@ class DocumentRepository {
       ([storageCost, processingCost] = await this.storage.getDrive()
         .updateDocument(
           document,
-          new Date('2022-03-17T15:08:26.132Z'),
+          blockInfo.getTime(),
           Boolean(options.useTransaction),
           Boolean(options.dryRun),
         ));
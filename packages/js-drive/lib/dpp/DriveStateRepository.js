@@ -3,6 +3,8 @@ const { TYPES } = require('@dashevo/dpp/lib/identity/IdentityPublicKey');
 const ReadOperation = require('@dashevo/dpp/lib/stateTransition/fee/operations/ReadOperation');
 const SignatureVerificationOperation = require('@dashevo/dpp/lib/stateTransition/fee/operations/SignatureVerificationOperation');
 
+const BlockInfo = require('../blockExecution/BlockInfo');
+
 /**
  * @implements StateRepository
  */
@@ -234,6 +236,7 @@ class DriveStateRepository {
   async storeDataContract(dataContract, executionContext = undefined) {
     const result = await this.dataContractRepository.store(
       dataContract,
+      BlockInfo.createFromBlockExecutionContext(this.blockExecutionContext),
       this.#createRepositoryOptions(executionContext),
     );
 
@@ -280,6 +283,7 @@ class DriveStateRepository {
   async createDocument(document, executionContext = undefined) {
     const result = await this.documentRepository.create(
       document,
+      BlockInfo.createFromBlockExecutionContext(this.blockExecutionContext),
       this.#createRepositoryOptions(executionContext),
     );
 
@@ -299,6 +303,7 @@ class DriveStateRepository {
   async updateDocument(document, executionContext = undefined) {
     const result = await this.documentRepository.update(
       document,
+      BlockInfo.createFromBlockExecutionContext(this.blockExecutionContext),
       this.#createRepositoryOptions(executionContext),
     );
 
@@ -25,9 +25,10 @@ function handleNewMasternodeFactory(
    * @typedef handleNewMasternode
    * @param {SimplifiedMNListEntry} masternodeEntry
    * @param {DataContract} dataContract
+   * @param {BlockInfo} blockInfo
    * @return Promise<Array<Identity|Document>>
    */
-  async function handleNewMasternode(masternodeEntry, dataContract) {
+  async function handleNewMasternode(masternodeEntry, dataContract, blockInfo) {
     const result = [];
 
     const { extraPayload: proRegTxPayload } = await fetchTransaction(masternodeEntry.proRegTxHash);
@@ -85,6 +86,7 @@ function handleNewMasternodeFactory(
         masternodeIdentifier,
         operatorIdentifier,
         proRegTxPayload.operatorReward,
+        blockInfo,
       );
 
       if (rewardShareDocument) {
@@ -37,6 +37,7 @@ function synchronizeMasternodeIdentitiesFactory(
   /**
    * @typedef synchronizeMasternodeIdentities
    * @param {number} coreHeight
+   * @param {BlockInfo} blockInfo
    * @return {Promise<{
    *  created: Array<Identity|Document>,
    *  updated: Array<Identity|Document>,
@@ -45,7 +46,7 @@ function synchronizeMasternodeIdentitiesFactory(
    *  toHeight: number,
    * }>}
    */
-  async function synchronizeMasternodeIdentities(coreHeight) {
+  async function synchronizeMasternodeIdentities(coreHeight, blockInfo) {
     if (!lastSyncedCoreHeight) {
       const lastSyncedHeightResult = await lastSyncedCoreHeightRepository.fetch({
         useTransaction: true,
@@ -109,6 +110,7 @@ function synchronizeMasternodeIdentitiesFactory(
               mnEntry,
               previousMnEntry,
               dataContract,
+              blockInfo,
             ),
           );
         }
@@ -177,7 +179,7 @@ function synchronizeMasternodeIdentitiesFactory(
 
     for (const newMasternodeEntry of newMasternodes) {
       createdEntities = createdEntities.concat(
-        await handleNewMasternode(newMasternodeEntry, dataContract),
+        await handleNewMasternode(newMasternodeEntry, dataContract, blockInfo),
       );
     }
 
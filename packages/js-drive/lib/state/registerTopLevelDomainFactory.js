@@ -19,11 +19,16 @@ function registerTopLevelDomainFactory(
    * @param {string} name
    * @param {DataContract} dataContract
    * @param {Identifier} ownerId
-   * @param {Date} genesisDate
+   * @param {BlockInfo} genesisBlockInfo
    *
    * @return {Promise<void>}
    */
-  async function registerTopLevelDomain(name, dataContract, ownerId, genesisDate) {
+  async function registerTopLevelDomain(
+    name,
+    dataContract,
+    ownerId,
+    genesisBlockInfo,
+  ) {
     const normalizedParentDomainName = '';
     const normalizedLabel = name.toLowerCase();
 
@@ -46,9 +51,12 @@ function registerTopLevelDomainFactory(
     );
 
     domainDocument.id = dashDomainDocumentId;
-    domainDocument.createdAt = genesisDate;
+    // Domain creation time is kept in whole seconds
+    domainDocument.createdAt = new Date(
+      Math.floor(genesisBlockInfo.getTimeMs() / 1000) * 1000,
+    );
 
-    await documentRepository.create(domainDocument, {
+    await documentRepository.create(domainDocument, genesisBlockInfo, {
       useTransaction: true,
     });
   }
@@ -2,6 +2,7 @@ const { startDashCore } = require('@dashevo/dp-services-ctl');
 const SimplifiedMNListStore = require('@dashevo/dashcore-lib/lib/deterministicmnlist/SimplifiedMNListStore');
 
 const createTestDIContainer = require('../../../lib/test/createTestDIContainer');
+const BlockInfo = require('../../../lib/blockExecution/BlockInfo');
 
 describe('updateSimplifiedMasternodeListFactory', function main() {
   this.timeout(190000);
@@ -61,6 +62,8 @@ describe('updateSimplifiedMasternodeListFactory', function main() {
     const synchronizeMasternodeIdentities = container.resolve('synchronizeMasternodeIdentities');
     const smlMaxListsLimit = container.resolve('smlMaxListsLimit');
 
+    const blockInfo = new BlockInfo(1, Date.now());
+
     const api = dashCore.getApi();
     const { result: randomAddress } = await api.getNewAddress();
 
@@ -69,7 +72,7 @@ describe('updateSimplifiedMasternodeListFactory', function main() {
     let blockNumber = 500;
 
     await updateSimplifiedMasternodeList(blockNumber);
-    await synchronizeMasternodeIdentities(blockNumber);
+    await synchronizeMasternodeIdentities(blockNumber, blockInfo);
 
     expect(simplifiedMasternodeList.getStore())
       .to.be.an.instanceOf(SimplifiedMNListStore);
@@ -77,7 +80,7 @@ describe('updateSimplifiedMasternodeListFactory', function main() {
     blockNumber += smlMaxListsLimit;
 
     await updateSimplifiedMasternodeList(blockNumber);
-    await synchronizeMasternodeIdentities(blockNumber);
+    await synchronizeMasternodeIdentities(blockNumber, blockInfo);
 
     expect(simplifiedMasternodeList.getStore())
       .to.be.an.instanceOf(SimplifiedMNListStore);
@@ -85,7 +88,7 @@ describe('updateSimplifiedMasternodeListFactory', function main() {
     blockNumber += smlMaxListsLimit;
 
     await updateSimplifiedMasternodeList(blockNumber);
-    await synchronizeMasternodeIdentities(blockNumber);
+    await synchronizeMasternodeIdentities(blockNumber, blockInfo);
 
     expect(simplifiedMasternodeList.getStore())
       .to.be.an.instanceOf(SimplifiedMNListStore);
@@ -8,6 +8,7 @@ const GroveDBStore = require('../../../lib/storage/GroveDBStore');
 const DataContractStoreRepository = require('../../../lib/dataContract/DataContractStoreRepository');
 const noopLogger = require('../../../lib/util/noopLogger');
 const StorageResult = require('../../../lib/storage/StorageResult');
+const BlockInfo = require('../../../lib/blockExecution/BlockInfo');
 
 describe('DataContractStoreRepository', () => {
   let rsDrive;
@@ -15,6 +16,7 @@ describe('DataContractStoreRepository', () => {
   let repository;
   let decodeProtocolEntity;
   let dataContract;
+  let blockInfo;
 
   beforeEach(async () => {
     rsDrive = new Drive('./db/grovedb_test');
@@ -25,6 +27,8 @@ describe('DataContractStoreRepository', () => {
     repository = new DataContractStoreRepository(store, decodeProtocolEntity, noopLogger);
 
     dataContract = getDataContractFixture();
+
+    blockInfo = new BlockInfo(1, Date.now());
   });
 
   afterEach(async () => {
@@ -40,6 +44,7 @@ describe('DataContractStoreRepository', () => {
     it('should store Data Contract', async () => {
       const result = await repository.store(
         dataContract,
+        blockInfo,
       );
 
       expect(result).to.be.instanceOf(StorageResult);
@@ -66,6 +71,7 @@ describe('DataContractStoreRepository', () => {
 
       const result = await repository.store(
         dataContract,
+        blockInfo,
         { useTransaction: true },
       );
 
@@ -116,6 +122,7 @@ describe('DataContractStoreRepository', () => {
     it('should not store Data Contract with dry run', async () => {
       const result = await repository.store(
         dataContract,
+        blockInfo,
         { dryRun: true },
       );
 
@@ -129,6 +136,19 @@ describe('DataContractStoreRepository', () => {
 
       expect(encodedDataContractResult.getValue()).to.be.null();
     });
+
+    it('should pass block time to RS Drive', async function it() {
+      const applyContractSpy = this.sinon.spy(store.getDrive(), 'applyContract');
+
+      await repository.store(dataContract, blockInfo);
+
+      expect(applyContractSpy).to.be.calledOnce();
+
+      const [, blockTime] = applyContractSpy.getCall(0).args;
+
+      expect(blockTime).to.deep.equal(blockInfo.getTime());
+      expect(blockTime).to.not.deep.equal(new Date('2022-03-17T15:08:26.132Z'));
+    });
   });
 
   describe('#fetch', () => {
@@ -9,6 +9,7 @@ const createTestDIContainer = require('../../../lib/test/createTestDIContainer')
 const createDocumentTypeTreePath = require('../../../lib/document/groveDB/createDocumentTreePath');
 const InvalidQueryError = require('../../../lib/document/errors/InvalidQueryError');
 const StorageResult = require('../../../lib/storage/StorageResult');
+const BlockInfo = require('../../../lib/blockExecution/BlockInfo');
 
 function ucFirst(string) {
   return string.charAt(0).toUpperCase() + string.slice(1);
@@ -653,10 +654,10 @@ const invalidQueries = [
 
 const invalidOperators = ['<<', '<==', '===', '!>', '>>='];
 
-async function createDocuments(documentRepository, documents) {
+async function createDocuments(documentRepository, documents, blockInfo) {
   return Promise.all(
     documents.map(async (o) => {
-      const result = await documentRepository.create(o);
+      const result = await documentRepository.create(o, blockInfo);
 
       expect(result).to.be.instanceOf(StorageResult);
       expect(result.getOperations().length).to.be.greaterThan(0);
@@ -675,11 +676,14 @@ describe('DocumentRepository', function main() {
   let documents;
   let document;
   let documentSchema;
+  let blockInfo;
 
   beforeEach(async () => {
     const now = 86400;
     container = await createTestDIContainer();
 
+    blockInfo = new BlockInfo(1, Date.now());
+
     dataContract = getDataContractFixture();
     documents = getDocumentsFixture(dataContract).slice(0, 5);
 
@@ -798,8 +802,8 @@ describe('DocumentRepository', function main() {
 
     dataContractRepository = container.resolve('dataContractRepository');
 
-    await dataContractRepository.store(dataContract);
-    await dataContractRepository.store(queryDataContract);
+    await dataContractRepository.store(dataContract, blockInfo);
+    await dataContractRepository.store(queryDataContract, blockInfo);
   });
 
   afterEach(async () => {
@@ -810,7 +814,7 @@ describe('DocumentRepository', function main() {
 
   describe('#create', () => {
     beforeEach(async () => {
-      await createDocuments(documentRepository, documents);
+      await createDocuments(documentRepository, documents, blockInfo);
     });
 
     it('should create Document', async () => {
@@ -838,7 +842,7 @@ describe('DocumentRepository', function main() {
         .storage
         .startTransaction();
 
-      await documentRepository.create(document, {
+      await documentRepository.create(document, blockInfo, {
         useTransaction: true,
       });
 
@@ -881,7 +885,7 @@ describe('DocumentRepository', function main() {
     it('should not create Document on dry run', async () => {
       await documentRepository.delete(dataContract, document.getType(), document.getId());
 
-      await documentRepository.create(document, {
+      await documentRepository.create(document, blockInfo, {
         dryRun: true,
       });
 
@@ -905,13 +909,31 @@ describe('DocumentRepository', function main() {
         expect(e.message.startsWith('path key not found: key not found in Merk')).to.be.true();
       }
     });
+
+    it('should pass block time to RS Drive', async function it() {
+      await documentRepository.delete(dataContract, document.getType(), document.getId());
+
+      const createDocumentSpy = this.sinon.spy(
+        documentRepository.storage.getDrive(),
+        'createDocument',
+      );
+
+      await documentRepository.create(document, blockInfo);
+
+      expect(createDocumentSpy).to.be.calledOnce();
+
+      const [, blockTime] = createDocumentSpy.getCall(0).args;
+
+      expect(blockTime).to.deep.equal(blockInfo.getTime());
+      expect(blockTime).to.not.deep.equal(new Date('2022-03-17T15:08:26.132Z'));
+    });
   });
 
   describe('#update', () => {
     let replaceDocument;
 
     beforeEach(async () => {
-      await createDocuments(documentRepository, documents);
+      await createDocuments(documentRepository, documents, blockInfo);
 
       replaceDocument = new Document({
         ...documents[1].toObject(),
@@ -920,7 +942,7 @@ describe('DocumentRepository', function main() {
     });
 
     it('should update Document', async () => {
-      const updateResult = await documentRepository.update(replaceDocument);
+      const updateResult = await documentRepository.update(replaceDocument, blockInfo);
 
       expect(updateResult).to.be.instanceOf(StorageResult);
       expect(updateResult.getOperations().length).to.be.greaterThan(0);
@@ -947,7 +969,7 @@ describe('DocumentRepository', function main() {
         .storage
         .startTransaction();
 
-      const updateResult = await documentRepository.update(replaceDocument, {
+      const updateResult = await documentRepository.update(replaceDocument, blockInfo, {
         useTransaction: true,
       });
 
@@ -986,7 +1008,7 @@ describe('DocumentRepository', function main() {
     });
 
     it('should not update Document on dry run', async () => {
-      const updateResult = await documentRepository.update(replaceDocument, {
+      const updateResult = await documentRepository.update(replaceDocument, blockInfo, {
         dryRun: true,
       });
 
@@ -1009,11 +1031,27 @@ describe('DocumentRepository', function main() {
 
       expect(documents[1].toBuffer()).to.deep.equal(notUpdatedDocument.value);
     });
+
+    it('should pass block time to RS Drive', async function it() {
+      const updateDocumentSpy = this.sinon.spy(
+        documentRepository.storage.getDrive(),
+        'updateDocument',
+      );
+
+      await documentRepository.update(replaceDocument, blockInfo);
+
+      expect(updateDocumentSpy).to.be.calledOnce();
+
+      const [, blockTime] = updateDocumentSpy.getCall(0).args;
+
+      expect(blockTime).to.deep.equal(blockInfo.getTime());
+      expect(blockTime).to.not.deep.equal(new Date('2022-03-17T15:08:26.132Z'));
+    });
   });
 
   describe('#find', () => {
     beforeEach(async () => {
-      await createDocuments(documentRepository, documents);
+      await createDocuments(documentRepository, documents, blockInfo);
     });
 
     it('should find all existing documents', async () => {
@@ -1122,7 +1160,7 @@ describe('DocumentRepository', function main() {
           const factory = new DataContractFactory(createDPPMock(), () => {});
           const ownerId = generateRandomIdentifier();
           const myDataContract = factory.create(ownerId, schema);
-          await dataContractRepository.store(myDataContract);
+          await dataContractRepository.store(myDataContract, blockInfo);
 
           const result = await documentRepository.find(myDataContract, 'chat', {
             where: [
@@ -1167,7 +1205,7 @@ describe('DocumentRepository', function main() {
           const factory = new DataContractFactory(createDPPMock(), () => {});
           const ownerId = generateRandomIdentifier();
           const myDataContract = factory.create(ownerId, schema);
-          await dataContractRepository.store(myDataContract);
+          await dataContractRepository.store(myDataContract, blockInfo);
 
           const result = await documentRepository.find(myDataContract, 'label', {
             where: [
@@ -2491,7 +2529,7 @@ describe('DocumentRepository', function main() {
             const svDoc = document;
 
             svDoc.id = Identifier.from(Buffer.alloc(32, i + 1));
-            await documentRepository.create(svDoc);
+            await documentRepository.create(svDoc, blockInfo);
           }
 
           const result = await documentRepository.find(dataContract, document.getType());
@@ -2830,7 +2868,7 @@ describe('DocumentRepository', function main() {
           let i = 0;
           for (const svDoc of documents) {
             svDoc.id = Identifier.from(Buffer.alloc(32, i + 1));
-            await documentRepository.create(svDoc);
+            await documentRepository.create(svDoc, blockInfo);
             i++;
             createdIds.push(svDoc.id);
           }
@@ -3083,7 +3121,7 @@ describe('DocumentRepository', function main() {
 
   describe('#delete', () => {
     beforeEach(async () => {
-      await createDocuments(documentRepository, documents);
+      await createDocuments(documentRepository, documents, blockInfo);
     });
 
     it('should delete Document', async () => {
@@ -3249,7 +3287,7 @@ describe('DocumentRepository', function main() {
     // TODO do we need to check prove result with every single find test request?
 
     beforeEach(async () => {
-      await createDocuments(documentRepository, documents);
+      await createDocuments(documentRepository, documents, blockInfo);
     });
 
     it('should return proof for all existing documents', async () => {
@@ -3288,7 +3326,7 @@ describe('DocumentRepository', function main() {
 
   describe('#proveManyDocumentsFromDifferentContracts', () => {
     beforeEach(async () => {
-      await createDocuments(documentRepository, documents);
+      await createDocuments(documentRepository, documents, blockInfo);
     });
 
     it('should return proof for all existing documents', async () => {
@@ -5,6 +5,7 @@ const InvalidQueryError = require('../../../lib/document/errors/InvalidQueryErro
 
 const createTestDIContainer = require('../../../lib/test/createTestDIContainer');
 const StorageResult = require('../../../lib/storage/StorageResult');
+const BlockInfo = require('../../../lib/blockExecution/BlockInfo');
 
 describe('fetchDataContractFactory', () => {
   let fetchDataContract;
@@ -12,10 +13,13 @@ describe('fetchDataContractFactory', () => {
   let dataContractRepository;
   let dataContract;
   let container;
+  let blockInfo;
 
   beforeEach(async () => {
     container = await createTestDIContainer();
 
+    blockInfo = new BlockInfo(1, Date.now());
+
     dataContractRepository = container.resolve('dataContractRepository');
 
     dataContract = getDataContractFixture();
@@ -28,7 +32,7 @@ describe('fetchDataContractFactory', () => {
     const rsDrive = container.resolve('rsDrive');
     await rsDrive.createInitialStateStructure();
 
-    await dataContractRepository.store(dataContract);
+    await dataContractRepository.store(dataContract, blockInfo);
 
     fetchDataContract = container.resolve('fetchDataContract');
   });
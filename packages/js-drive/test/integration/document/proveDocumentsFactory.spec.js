@@ -2,6 +2,7 @@ const getDataContractFixture = require('@dashevo/dpp/lib/test/fixtures/getDataCo
 const getDocumentsFixture = require('@dashevo/dpp/lib/test/fixtures/getDocumentsFixture');
 const createTestDIContainer = require('../../../lib/test/createTestDIContainer');
 const StorageResult = require('../../../lib/storage/StorageResult');
+const BlockInfo = require('../../../lib/blockExecution/BlockInfo');
 const InvalidQueryError = require('../../../lib/document/errors/InvalidQueryError');
 
 describe('proveDocumentsFactory', () => {
@@ -13,10 +14,13 @@ describe('proveDocumentsFactory', () => {
   let documentRepository;
   let dataContract;
   let container;
+  let blockInfo;
 
   beforeEach(async () => {
     container = await createTestDIContainer();
 
+    blockInfo = new BlockInfo(1, Date.now());
+
     dataContractRepository = container.resolve('dataContractRepository');
     documentRepository = container.resolve('documentRepository');
 
@@ -42,7 +46,7 @@ describe('proveDocumentsFactory', () => {
     const rsDrive = container.resolve('rsDrive');
     await rsDrive.createInitialStateStructure();
 
-    await dataContractRepository.store(dataContract);
+    await dataContractRepository.store(dataContract, blockInfo);
 
     proveDocuments = container.resolve('proveDocuments');
   });
@@ -54,7 +58,7 @@ describe('proveDocumentsFactory', () => {
   });
 
   it('should return proof for specified contract ID and document type', async () => {
-    await documentRepository.create(document);
+    await documentRepository.create(document, blockInfo);
 
     const result = await proveDocuments(contractId, documentType);
 
@@ -68,7 +72,7 @@ describe('proveDocumentsFactory', () => {
   });
 
   it('should return proof for specified contract id, document type and name', async () => {
-    await documentRepository.create(document);
+    await documentRepository.create(document, blockInfo);
 
     const query = { where: [['name', '==', document.get('name')]] };
 
@@ -81,7 +85,7 @@ describe('proveDocumentsFactory', () => {
   });
 
   it('should return proof for specified contract ID, document type and name not exist', async () => {
-    await documentRepository.create(document);
+    await documentRepository.create(document, blockInfo);
 
     const query = { where: [['name', '==', 'unknown']] };
 
@@ -99,7 +103,7 @@ describe('proveDocumentsFactory', () => {
   it('should return proof by an equal date', async () => {
     const indexedDocument = getDocumentsFixture(dataContract)[3];
 
-    await documentRepository.create(indexedDocument);
+    await documentRepository.create(indexedDocument, blockInfo);
 
     const query = {
       where: [
@@ -121,7 +125,7 @@ describe('proveDocumentsFactory', () => {
   it('should return proof by a date range', async () => {
     const [, , , indexedDocument] = getDocumentsFixture(dataContract);
 
-    await documentRepository.create(indexedDocument);
+    await documentRepository.create(indexedDocument, blockInfo);
 
     const startDate = new Date();
     startDate.setSeconds(startDate.getSeconds() - 10);
@@ -151,7 +155,7 @@ describe('proveDocumentsFactory', () => {
   it('should fetch empty array in case date is out of range', async () => {
     const [, , , indexedDocument] = getDocumentsFixture(dataContract);
 
-    await documentRepository.create(indexedDocument);
+    await documentRepository.create(indexedDocument, blockInfo);
 
     const startDate = new Date();
     startDate.setSeconds(startDate.getSeconds() + 10);
@@ -179,7 +183,7 @@ describe('proveDocumentsFactory', () => {
   });
 
   it('should throw InvalidQueryError if searching by non indexed fields', async () => {
-    await documentRepository.create(document);
+    await documentRepository.create(document, blockInfo);
 
     const query = { where: [['lastName', '==', 'unknown']] };
 
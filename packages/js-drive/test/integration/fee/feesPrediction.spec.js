@@ -112,6 +112,7 @@ describe('feesPrediction', () => {
     blockExecutionContext.getTime = this.sinon.stub().returns(
       { seconds: new Date().getTime() / 1000 },
     );
+    blockExecutionContext.getHeight = this.sinon.stub().returns(1);
 
     dpp = container.resolve('dpp');
 
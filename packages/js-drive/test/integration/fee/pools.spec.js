@@ -10,6 +10,7 @@ const getIdentityFixture = require('@dashevo/dpp/lib/test/fixtures/getIdentityFi
 const Identifier = require('@dashevo/dpp/lib/identifier/Identifier');
 const generateRandomIdentifier = require('@dashevo/dpp/lib/test/utils/generateRandomIdentifier');
 const createTestDIContainer = require('../../../lib/test/createTestDIContainer');
+const BlockInfo = require('../../../lib/blockExecution/BlockInfo');
 
 describe('Fee Pools', () => {
   let container;
@@ -30,11 +31,13 @@ describe('Fee Pools', () => {
     rsDrive = container.resolve('rsDrive');
     await rsDrive.getAbci().initChain({});
 
+    const blockInfo = new BlockInfo(1, Date.now());
+
     // setup mn reward shares contract
     const mnSharesContract = getMasternodeRewardSharesContractFixture();
     mnSharesContract.id = Identifier.from(masternodeRewardSharesSystemIds.contractId);
 
-    await dataContractRepository.store(mnSharesContract);
+    await dataContractRepository.store(mnSharesContract, blockInfo);
 
     mnDatas = [];
     const mnCount = 1;
@@ -52,7 +55,7 @@ describe('Fee Pools', () => {
 
       await identityRepository.create(mnIdentity);
       await identityRepository.create(payToIdentity);
-      await documentRepository.create(payToDocument);
+      await documentRepository.create(payToDocument, blockInfo);
 
       mnDatas.push({
         mnIdentity,
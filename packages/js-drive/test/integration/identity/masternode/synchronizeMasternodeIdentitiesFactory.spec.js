@@ -12,6 +12,7 @@ const Script = require('@dashevo/dashcore-lib/lib/script');
 const createTestDIContainer = require('../../../../lib/test/createTestDIContainer');
 const createOperatorIdentifier = require('../../../../lib/identity/masternode/createOperatorIdentifier');
 const createVotingIdentifier = require('../../../../lib/identity/masternode/createVotingIdentifier');
+const BlockInfo = require('../../../../lib/blockExecution/BlockInfo');
 
 /**
  * @param {IdentityStoreRepository} identityRepository
@@ -352,6 +353,8 @@ describe('synchronizeMasternodeIdentitiesFactory', () => {
   let expectMasternodeIdentity;
   let expectDeterministicAppHash;
   let firstSyncAppHash;
+  let blockExecutionContext;
+  let blockInfo;
 
   beforeEach(async function beforeEach() {
     coreHeight = 3;
@@ -359,11 +362,13 @@ describe('synchronizeMasternodeIdentitiesFactory', () => {
 
     container = await createTestDIContainer();
 
-    const blockExecutionContext = container.resolve('blockExecutionContext');
-    blockExecutionContext.getTime = this.sinon.stub().returns(
+    blockExecutionContext = container.resolve('blockExecutionContext');
+    this.sinon.stub(blockExecutionContext, 'getTime').returns(
       { seconds: 1651585250 },
     );
 
+    blockInfo = new BlockInfo(1, 1651585250000);
+
     // Mock fetchTransaction
 
     fetchTransactionMock = this.sinon.stub();
@@ -460,6 +465,7 @@ describe('synchronizeMasternodeIdentitiesFactory', () => {
       masternodeRewardSharesOwnerMasterPublicKey,
       masternodeRewardSharesOwnerSecondPublicKey,
       masternodeRewardSharesDocuments,
+      blockInfo,
     );
 
     synchronizeMasternodeIdentities = container.resolve('synchronizeMasternodeIdentities');
@@ -501,7 +507,7 @@ describe('synchronizeMasternodeIdentitiesFactory', () => {
   });
 
   it('should create identities for all masternodes on the first sync', async () => {
-    const result = await synchronizeMasternodeIdentities(coreHeight);
+    const result = await synchronizeMasternodeIdentities(coreHeight, blockInfo);
 
     expect(result.fromHeight).to.be.equal(0);
     expect(result.toHeight).to.be.equal(3);
@@ -631,16 +637,48 @@ describe('synchronizeMasternodeIdentitiesFactory', () => {
     expect(documents).to.have.lengthOf(0);
   });
 
+  it('should create reward shares on the first sync without block execution context', async () => {
+    // InitChain syncs masternodes before any block execution context is set
+    blockExecutionContext.getTime.callThrough();
+    blockExecutionContext.reset();
+
+    expect(blockExecutionContext.getHeight()).to.be.null();
+    expect(blockExecutionContext.getTime()).to.be.null();
+
+    const result = await synchronizeMasternodeIdentities(coreHeight, blockInfo);
+
+    expect(result.createdEntities).to.have.lengthOf(6);
+
+    await expectDeterministicAppHash(firstSyncAppHash);
+
+    const masternodeIdentifier = Identifier.from(
+      Buffer.from(smlFixture[0].proRegTxHash, 'hex'),
+    );
+
+    const documentsResult = await documentRepository.find(
+      rewardsDataContract,
+      'rewardShare',
+      {
+        where: [
+          ['$ownerId', '==', masternodeIdentifier],
+          ['payToId', '==', createOperatorIdentifier(smlFixture[0])],
+        ],
+      },
+    );
+
+    expect(documentsResult.getValue()).to.have.lengthOf(1);
+  });
+
   it('should sync identities if the gap between coreHeight and lastSyncedCoreHeight > smlMaxListsLimit', async () => {
     // Sync initial list
 
-    await synchronizeMasternodeIdentities(coreHeight);
+    await synchronizeMasternodeIdentities(coreHeight, blockInfo);
 
     await expectDeterministicAppHash(firstSyncAppHash);
 
     // Second call
 
-    const result = await synchronizeMasternodeIdentities(coreHeight + 42);
+    const result = await synchronizeMasternodeIdentities(coreHeight + 42, blockInfo);
 
     expect(result.fromHeight).to.be.equal(3);
     expect(result.toHeight).to.be.equal(45);
@@ -660,7 +698,7 @@ describe('synchronizeMasternodeIdentitiesFactory', () => {
   it('should create masternode identities if new masternode appeared', async () => {
     // Sync initial list
 
-    await synchronizeMasternodeIdentities(coreHeight);
+    await synchronizeMasternodeIdentities(coreHeight, blockInfo);
 
     await expectDeterministicAppHash(firstSyncAppHash);
 
@@ -697,7 +735,7 @@ describe('synchronizeMasternodeIdentitiesFactory', () => {
 
     // Second call
 
-    const result = await synchronizeMasternodeIdentities(coreHeight + 1);
+    const result = await synchronizeMasternodeIdentities(coreHeight + 1, blockInfo);
 
     expect(result.fromHeight).to.be.equal(3);
     expect(result.toHeight).to.be.equal(4);
@@ -768,7 +806,7 @@ describe('synchronizeMasternodeIdentitiesFactory', () => {
   it('should remove reward shares if masternode disappeared', async () => {
     // Sync initial list
 
-    await synchronizeMasternodeIdentities(coreHeight);
+    await synchronizeMasternodeIdentities(coreHeight, blockInfo);
 
     await expectDeterministicAppHash(firstSyncAppHash);
 
@@ -780,7 +818,7 @@ describe('synchronizeMasternodeIdentitiesFactory', () => {
 
     // Second call
 
-    const result = await synchronizeMasternodeIdentities(coreHeight + 1);
+    const result = await synchronizeMasternodeIdentities(coreHeight + 1, blockInfo);
 
     expect(result.fromHeight).to.be.equal(3);
     expect(result.toHeight).to.be.equal(4);
@@ -832,7 +870,7 @@ describe('synchronizeMasternodeIdentitiesFactory', () => {
   it('should remove reward shares if masternode is not valid', async () => {
     // Sync initial list
 
-    await synchronizeMasternodeIdentities(coreHeight);
+    await synchronizeMasternodeIdentities(coreHeight, blockInfo);
 
     await expectDeterministicAppHash(firstSyncAppHash);
 
@@ -847,7 +885,7 @@ describe('synchronizeMasternodeIdentitiesFactory', () => {
 
     // Second call
 
-    const result = await synchronizeMasternodeIdentities(coreHeight + 1);
+    const result = await synchronizeMasternodeIdentities(coreHeight + 1, blockInfo);
 
     expect(result.fromHeight).to.be.equal(3);
     expect(result.toHeight).to.be.equal(4);
@@ -881,7 +919,7 @@ describe('synchronizeMasternodeIdentitiesFactory', () => {
   it('should create operator identity and reward shares if PubKeyOperator was changed', async () => {
     // Initial sync
 
-    await synchronizeMasternodeIdentities(coreHeight);
+    await synchronizeMasternodeIdentities(coreHeight, blockInfo);
 
     await expectDeterministicAppHash(firstSyncAppHash);
 
@@ -896,7 +934,7 @@ describe('synchronizeMasternodeIdentitiesFactory', () => {
 
     // Second call
 
-    const result = await synchronizeMasternodeIdentities(coreHeight + 1);
+    const result = await synchronizeMasternodeIdentities(coreHeight + 1, blockInfo);
 
     expect(result.fromHeight).to.be.equal(3);
     expect(result.toHeight).to.be.equal(4);
@@ -960,7 +998,7 @@ describe('synchronizeMasternodeIdentitiesFactory', () => {
   it('should handle changed payout, voting and operator payout addresses', async () => {
     // Sync initial list
 
-    await synchronizeMasternodeIdentities(coreHeight);
+    await synchronizeMasternodeIdentities(coreHeight, blockInfo);
 
     await expectDeterministicAppHash(firstSyncAppHash);
 
@@ -976,7 +1014,7 @@ describe('synchronizeMasternodeIdentitiesFactory', () => {
 
     // Second call
 
-    await synchronizeMasternodeIdentities(coreHeight + 1);
+    await synchronizeMasternodeIdentities(coreHeight + 1, blockInfo);
 
     await expectDeterministicAppHash('3d930d58d397094e5fd14b75263b4745d67816c0adfa71c2ada41d2b046a4160');
 
@@ -1058,7 +1096,7 @@ describe('synchronizeMasternodeIdentitiesFactory', () => {
 
     // Initial sync
 
-    await synchronizeMasternodeIdentities(coreHeight);
+    await synchronizeMasternodeIdentities(coreHeight, blockInfo);
 
     await expectDeterministicAppHash('18071f3a6eba7dd5e9a31a314b2c74b62c2b41c729705f905741897bb7e0d825');
 
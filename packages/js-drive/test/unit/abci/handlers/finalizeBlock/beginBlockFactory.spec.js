@@ -8,6 +8,7 @@ const NotSupportedNetworkProtocolVersionError = require('../../../../../lib/abci
 const NetworkProtocolVersionIsNotSetError = require('../../../../../lib/abci/handlers/errors/NetworkProtocolVersionIsNotSetError');
 const GroveDBStoreMock = require('../../../../../lib/test/mock/GroveDBStoreMock');
 const BlockExecutionContextStackMock = require('../../../../../lib/test/mock/BlockExecutionContextStackMock');
+const BlockInfo = require('../../../../../lib/blockExecution/BlockInfo');
 
 describe('beginBlockFactory', () => {
   let protocolVersion;
@@ -84,6 +85,7 @@ describe('beginBlockFactory', () => {
 
     time = {
       seconds: Math.ceil(new Date().getTime() / 1000),
+      nanos: 0,
     };
 
     proposerProTxHash = Buffer.alloc(32, 1);
@@ -139,9 +141,14 @@ describe('beginBlockFactory', () => {
 
     await beginBlock(request, loggerMock);
 
-    expect(synchronizeMasternodeIdentitiesMock).to.have.been.calledOnceWithExactly(
-      coreChainLockedHeight,
-    );
+    expect(synchronizeMasternodeIdentitiesMock).to.have.been.calledOnce();
+
+    const [coreHeight, blockInfo] = synchronizeMasternodeIdentitiesMock.getCall(0).args;
+
+    expect(coreHeight).to.equal(coreChainLockedHeight);
+    expect(blockInfo).to.be.an.instanceOf(BlockInfo);
+    expect(blockInfo.getHeight()).to.equal(blockHeight.toNumber());
+    expect(blockInfo.getTimeMs()).to.equal(time.seconds * 1000);
   });
 
   it('should throw NotSupportedNetworkProtocolVersionError if protocol version is not supported', async () => {
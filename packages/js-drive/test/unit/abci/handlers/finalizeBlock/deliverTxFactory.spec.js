@@ -10,6 +10,8 @@ const getDataContractFixture = require('@dashevo/dpp/lib/test/fixtures/getDataCo
 const getDocumentFixture = require('@dashevo/dpp/lib/test/fixtures/getDocumentsFixture');
 const GrpcErrorCodes = require('@dashevo/grpc-common/lib/server/error/GrpcErrorCodes');
 const SomeConsensusError = require('@dashevo/dpp/lib/test/mocks/SomeConsensusError');
+const PreCalculatedOperation = require('@dashevo/dpp/lib/stateTransition/fee/operations/PreCalculatedOperation');
+const { FEE_MULTIPLIER } = require('@dashevo/dpp/lib/stateTransition/fee/constants');
 const BlockExecutionContextMock = require('../../../../../lib/test/mock/BlockExecutionContextMock');
 
 const deliverTxFactory = require('../../../../../lib/abci/handlers/finalizeBlock/deliverTxFactory');
@@ -159,6 +161,26 @@ describe('deliverTxFactory', () => {
     ).to.have.length(0);
   });
 
+  it('should accumulate storage and processing fees of applied operations separately', async () => {
+    unserializeStateTransitionMock.resolves(documentsBatchTransitionFixture);
+
+    dppMock.stateTransition.apply.callsFake(async (stateTransition) => {
+      stateTransition.getExecutionContext().addOperation(
+        new PreCalculatedOperation(100, 1),
+        new PreCalculatedOperation(20, 3),
+      );
+    });
+
+    await deliverTxHandler(documentTx, loggerMock);
+
+    expect(blockExecutionContextMock.incrementCumulativeStorageFee).to.be.calledOnceWithExactly(
+      120 * FEE_MULTIPLIER,
+    );
+    expect(blockExecutionContextMock.incrementCumulativeProcessingFee).to.be.calledOnceWithExactly(
+      4 * FEE_MULTIPLIER,
+    );
+  });
+
   it('should throw DPPValidationAbciError if a state transition is invalid against state', async () => {
     unserializeStateTransitionMock.resolves(dataContractCreateTransitionFixture);
 
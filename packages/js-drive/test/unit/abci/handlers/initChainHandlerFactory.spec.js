@@ -12,6 +12,7 @@ const {
 const initChainHandlerFactory = require('../../../../lib/abci/handlers/initChainHandlerFactory');
 const LoggerMock = require('../../../../lib/test/mock/LoggerMock');
 const GroveDBStoreMock = require('../../../../lib/test/mock/GroveDBStoreMock');
+const BlockInfo = require('../../../../lib/blockExecution/BlockInfo');
 
 describe('initChainHandlerFactory', () => {
   let initChainHandler;
@@ -107,10 +108,18 @@ describe('initChainHandlerFactory', () => {
 
     expect(rsAbciMock.initChain).to.be.calledOnceWithExactly({}, true);
 
-    expect(registerSystemDataContractsMock).to.be.calledOnceWithExactly(loggerMock, request.time);
+    expect(registerSystemDataContractsMock).to.be.calledOnce();
+
+    const [contextLogger, genesisBlockInfo] = registerSystemDataContractsMock.getCall(0).args;
+
+    expect(contextLogger).to.equal(loggerMock);
+    expect(genesisBlockInfo).to.be.an.instanceOf(BlockInfo);
+    expect(genesisBlockInfo.getHeight()).to.equal(1);
+    expect(genesisBlockInfo.getTimeMs()).to.equal(request.time.seconds.toNumber() * 1000);
 
     expect(synchronizeMasternodeIdentitiesMock).to.be.calledOnceWithExactly(
       initialCoreChainLockedHeight,
+      genesisBlockInfo,
     );
 
     expect(groveDBStoreMock.commitTransaction).to.be.calledOnce();
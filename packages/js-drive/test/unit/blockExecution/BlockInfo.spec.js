@@ -0,0 +1,69 @@
+const Long = require('long');
+
+const BlockInfo = require('../../../lib/blockExecution/BlockInfo');
+const BlockExecutionContext = require('../../../lib/blockExecution/BlockExecutionContext');
+const BlockExecutionContextMock = require('../../../lib/test/mock/BlockExecutionContextMock');
+const BlockExecutionContextIsNotSetError = require('../../../lib/blockExecution/errors/BlockExecutionContextIsNotSetError');
+
+describe('BlockInfo', () => {
+  let blockInfo;
+  let height;
+  let timeMs;
+
+  beforeEach(() => {
+    height = 42;
+    timeMs = 1647530906132;
+
+    blockInfo = new BlockInfo(height, timeMs);
+  });
+
+  describe('#getHeight', () => {
+    it('should return height', () => {
+      expect(blockInfo.getHeight()).to.equal(height);
+    });
+  });
+
+  describe('#getTimeMs', () => {
+    it('should return time in milliseconds', () => {
+      expect(blockInfo.getTimeMs()).to.equal(timeMs);
+    });
+  });
+
+  describe('#getTime', () => {
+    it('should return time as a Date', () => {
+      expect(blockInfo.getTime()).to.deep.equal(new Date(timeMs));
+    });
+  });
+
+  describe('#createFromBlockExecutionContext', () => {
+    it('should create block info from block execution context', function it() {
+      const blockExecutionContextMock = new BlockExecutionContextMock(this.sinon);
+
+      blockExecutionContextMock.getHeight.returns(Long.fromNumber(height));
+      blockExecutionContextMock.getTime.returns({
+        seconds: Long.fromNumber(1647530906),
+        nanos: 132000000,
+      });
+
+      const result = BlockInfo.createFromBlockExecutionContext(blockExecutionContextMock);
+
+      expect(result).to.be.an.instanceOf(BlockInfo);
+      expect(result.getHeight()).to.equal(height);
+      expect(result.getTimeMs()).to.equal(timeMs);
+    });
+
+    it('should throw BlockExecutionContextIsNotSetError if context is reset', () => {
+      const blockExecutionContext = new BlockExecutionContext();
+
+      blockExecutionContext.reset();
+
+      try {
+        BlockInfo.createFromBlockExecutionContext(blockExecutionContext);
+
+        expect.fail('should throw BlockExecutionContextIsNotSetError');
+      } catch (e) {
+        expect(e).to.be.an.instanceOf(BlockExecutionContextIsNotSetError);
+      }
+    });
+  });
+});
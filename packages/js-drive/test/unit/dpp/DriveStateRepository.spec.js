@@ -9,6 +9,7 @@ const StateTransitionExecutionContext = require('@dashevo/dpp/lib/stateTransitio
 const DriveStateRepository = require('../../../lib/dpp/DriveStateRepository');
 const StorageResult = require('../../../lib/storage/StorageResult');
 const BlockExecutionContextMock = require('../../../lib/test/mock/BlockExecutionContextMock');
+const BlockInfo = require('../../../lib/blockExecution/BlockInfo');
 
 describe('DriveStateRepository', () => {
   let stateRepository;
@@ -73,6 +74,11 @@ describe('DriveStateRepository', () => {
     };
 
     blockExecutionContextMock = new BlockExecutionContextMock(this.sinon);
+    blockExecutionContextMock.getHeight.returns(42);
+    blockExecutionContextMock.getTime.returns({
+      seconds: 1651585250,
+      nanos: 0,
+    });
 
     simplifiedMasternodeListMock = {
       getStore: this.sinon.stub(),
@@ -291,13 +297,20 @@ describe('DriveStateRepository', () => {
 
       await stateRepository.storeDataContract(dataContract, executionContext);
 
-      expect(dataContractRepositoryMock.store).to.be.calledOnceWith(
-        dataContract,
-        {
-          useTransaction: repositoryOptions.useTransaction,
-          dryRun: false,
-        },
-      );
+      expect(dataContractRepositoryMock.store).to.be.calledOnce();
+
+      const [passedDataContract, blockInfo, options] = dataContractRepositoryMock.store
+        .getCall(0).args;
+
+      expect(passedDataContract).to.equal(dataContract);
+      expect(options).to.deep.equal({
+        useTransaction: repositoryOptions.useTransaction,
+        dryRun: false,
+      });
+
+      expect(blockInfo).to.be.an.instanceOf(BlockInfo);
+      expect(blockInfo.getHeight()).to.equal(42);
+      expect(blockInfo.getTimeMs()).to.equal(1651585250000);
 
       expect(executionContext.getOperations()).to.deep.equals(operations);
     });
@@ -344,13 +357,19 @@ describe('DriveStateRepository', () => {
 
       await stateRepository.createDocument(document, executionContext);
 
-      expect(documentsRepositoryMock.create).to.be.calledOnceWith(
-        document,
-        {
-          useTransaction: repositoryOptions.useTransaction,
-          dryRun: false,
-        },
-      );
+      expect(documentsRepositoryMock.create).to.be.calledOnce();
+
+      const [passedDocument, blockInfo, options] = documentsRepositoryMock.create.getCall(0).args;
+
+      expect(passedDocument).to.equal(document);
+      expect(options).to.deep.equal({
+        useTransaction: repositoryOptions.useTransaction,
+        dryRun: false,
+      });
+
+      expect(blockInfo).to.be.an.instanceOf(BlockInfo);
+      expect(blockInfo.getHeight()).to.equal(42);
+      expect(blockInfo.getTimeMs()).to.equal(1651585250000);
 
       expect(executionContext.getOperations()).to.deep.equals(operations);
     });
@@ -366,13 +385,19 @@ describe('DriveStateRepository', () => {
 
       await stateRepository.updateDocument(document, executionContext);
 
-      expect(documentsRepositoryMock.update).to.be.calledOnceWith(
-        document,
-        {
-          useTransaction: repositoryOptions.useTransaction,
-          dryRun: false,
-        },
-      );
+      expect(documentsRepositoryMock.update).to.be.calledOnce();
+
+      const [passedDocument, blockInfo, options] = documentsRepositoryMock.update.getCall(0).args;
+
+      expect(passedDocument).to.equal(document);
+      expect(options).to.deep.equal({
+        useTransaction: repositoryOptions.useTransaction,
+        dryRun: false,
+      });
+
+      expect(blockInfo).to.be.an.instanceOf(BlockInfo);
+      expect(blockInfo.getHeight()).to.equal(42);
+      expect(blockInfo.getTimeMs()).to.equal(1651585250000);
 
       expect(executionContext.getOperations()).to.deep.equals(operations);
     });
@@ -9,6 +9,7 @@ const handleNewMasternodeFactory = require('../../../../lib/identity/masternode/
 const getSmlFixture = require('../../../../lib/test/fixtures/getSmlFixture');
 const createOperatorIdentifier = require('../../../../lib/identity/masternode/createOperatorIdentifier');
 const createVotingIdentifier = require('../../../../lib/identity/masternode/createVotingIdentifier');
+const BlockInfo = require('../../../../lib/blockExecution/BlockInfo');
 
 describe('handleNewMasternodeFactory', () => {
   let handleNewMasternode;
@@ -20,6 +21,7 @@ describe('handleNewMasternodeFactory', () => {
   let transactionFixture;
   let masternodeEntry;
   let dataContract;
+  let blockInfo;
 
   beforeEach(function beforeEach() {
     const smlFixture = getSmlFixture();
@@ -34,6 +36,8 @@ describe('handleNewMasternodeFactory', () => {
     createMasternodeIdentityMock = this.sinon.stub();
     createRewardShareDocumentMock = this.sinon.stub();
 
+    blockInfo = new BlockInfo(1, Date.now());
+
     transactionFixture = {
       extraPayload: {
         operatorReward: 0,
@@ -59,7 +63,7 @@ describe('handleNewMasternodeFactory', () => {
     const payoutAddress = Address.fromString(masternodeEntry.payoutAddress);
     const payoutScript = new Script(payoutAddress);
 
-    await handleNewMasternode(masternodeEntry, dataContract);
+    await handleNewMasternode(masternodeEntry, dataContract, blockInfo);
 
     expect(fetchTransactionMock).to.be.calledOnceWithExactly(masternodeEntry.proRegTxHash);
     expect(createMasternodeIdentityMock).to.be.calledTwice();
@@ -87,7 +91,7 @@ describe('handleNewMasternodeFactory', () => {
 
     fetchTransactionMock.resolves(transactionFixture);
 
-    await handleNewMasternode(masternodeEntry, dataContract);
+    await handleNewMasternode(masternodeEntry, dataContract, blockInfo);
 
     expect(fetchTransactionMock).to.be.calledOnceWithExactly(masternodeEntry.proRegTxHash);
     expect(createMasternodeIdentityMock).to.be.calledOnceWithExactly(
@@ -103,7 +107,7 @@ describe('handleNewMasternodeFactory', () => {
   it('should create masternode identity and a document in rewards data contract with percentage', async () => {
     transactionFixture.extraPayload.operatorReward = 10;
 
-    await handleNewMasternode(masternodeEntry, dataContract);
+    await handleNewMasternode(masternodeEntry, dataContract, blockInfo);
 
     const operatorIdentifier = createOperatorIdentifier(masternodeEntry);
     const operatorPayoutAddress = Address.fromString(masternodeEntry.operatorPayoutAddress);
@@ -136,6 +140,8 @@ describe('handleNewMasternodeFactory', () => {
       Identifier.from('6k8jXHFuno3vqpfrQ36CaxrGi4SupdTJcGNeZLPioxQo'),
       Identifier.from('EwLi1FgGwvmLQ9nkfnttpXzv4SfC7XGBvs61QBCtnHEL'),
       10,
+      blockInfo,
     );
+    expect(createRewardShareDocumentMock.getCall(0).args[4]).to.equal(blockInfo);
   });
 });
@@ -0,0 +1,74 @@
+const createDPPMock = require('@dashevo/dpp/lib/test/mocks/createDPPMock');
+const getDataContractFixture = require('@dashevo/dpp/lib/test/fixtures/getDataContractFixture');
+const generateRandomIdentifier = require('@dashevo/dpp/lib/test/utils/generateRandomIdentifier');
+const registerTopLevelDomainFactory = require('../../../lib/state/registerTopLevelDomainFactory');
+const BlockInfo = require('../../../lib/blockExecution/BlockInfo');
+
+describe('registerTopLevelDomainFactory', () => {
+  let registerTopLevelDomain;
+  let dppMock;
+  let documentRepositoryMock;
+  let dashDomainDocumentId;
+  let dashPreorderSalt;
+  let dataContract;
+  let ownerId;
+  let domainDocument;
+  let blockInfo;
+
+  beforeEach(function beforeEach() {
+    dppMock = createDPPMock(this.sinon);
+
+    domainDocument = {};
+    dppMock.document.create.resolves(domainDocument);
+
+    documentRepositoryMock = {
+      create: this.sinon.stub(),
+    };
+
+    dashDomainDocumentId = generateRandomIdentifier();
+    dashPreorderSalt = Buffer.alloc(32, 1);
+
+    dataContract = getDataContractFixture();
+    ownerId = generateRandomIdentifier();
+
+    blockInfo = new BlockInfo(1, 1647530906132);
+
+    registerTopLevelDomain = registerTopLevelDomainFactory(
+      dppMock,
+      documentRepositoryMock,
+      dashDomainDocumentId,
+      dashPreorderSalt,
+    );
+  });
+
+  it('should create top level domain document with block time in whole seconds', async () => {
+    await registerTopLevelDomain('Dash', dataContract, ownerId, blockInfo);
+
+    expect(dppMock.document.create).to.be.calledOnceWithExactly(
+      dataContract,
+      ownerId,
+      'domain',
+      {
+        label: 'Dash',
+        normalizedLabel: 'dash',
+        normalizedParentDomainName: '',
+        preorderSalt: dashPreorderSalt,
+        records: {
+          dashAliasIdentityId: ownerId,
+        },
+        subdomainRules: {
+          allowSubdomains: true,
+        },
+      },
+    );
+
+    expect(domainDocument.id).to.equal(dashDomainDocumentId);
+    expect(domainDocument.createdAt).to.deep.equal(new Date(1647530906000));
+
+    expect(documentRepositoryMock.create).to.be.calledOnceWithExactly(
+      domainDocument,
+      blockInfo,
+      { useTransaction: true },
+    );
+  });
+});